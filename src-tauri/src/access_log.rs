@@ -0,0 +1,166 @@
+// Structured access logging for `PythonServerClient` requests: records
+// method/path/port/status/size/duration for every call made through its
+// typed `get`/`post`/`delete` methods (requests built with `request()` and
+// sent by the caller, such as the file upload, are not recorded) to a
+// rotating file under the app data dir (plus `log` crate output), so a "cannot connect to
+// server" report has something concrete to attach instead of a single error
+// string.
+use std::fs::OpenOptions;
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::Duration;
+
+const MAX_LOG_FILE_SIZE: u64 = 5 * 1024 * 1024; // 5MB
+const MAX_LOG_BACKUP_COUNT: usize = 3;
+
+static LOG_FILE: Mutex<Option<PathBuf>> = Mutex::new(None);
+static VERBOSE: AtomicBool = AtomicBool::new(false);
+
+/// One logged interaction with the Python sidecar/remote backend.
+pub struct AccessLogEntry {
+    pub method: String,
+    pub path: String,
+    pub port: Option<u16>,
+    pub status: Option<u16>,
+    pub bytes: Option<u64>,
+    pub duration: Duration,
+    pub error: Option<String>,
+}
+
+pub fn init_access_log(app_data_dir: Option<&Path>) -> io::Result<()> {
+    let log_dir = if let Some(app_dir) = app_data_dir {
+        let log_dir = app_dir.join("logs");
+        std::fs::create_dir_all(&log_dir)?;
+        log_dir
+    } else {
+        PathBuf::from("logs")
+    };
+
+    let mut log_path = LOG_FILE.lock().unwrap();
+    *log_path = Some(log_dir.join("access.log"));
+
+    Ok(())
+}
+
+/// Toggles whether access-log lines are also surfaced at `log::info!` (vs.
+/// `log::debug!`); the file log always records every request regardless.
+pub fn set_verbose(verbose: bool) {
+    VERBOSE.store(verbose, Ordering::Relaxed);
+}
+
+pub fn is_verbose() -> bool {
+    VERBOSE.load(Ordering::Relaxed)
+}
+
+/// Records one request/response pair to the access log file and via the
+/// `log` crate.
+pub fn record(entry: AccessLogEntry) {
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+
+    let line = format!(
+        "[{}] {} {} port={} status={} bytes={} duration_ms={}{}",
+        timestamp,
+        entry.method,
+        entry.path,
+        entry
+            .port
+            .map(|p| p.to_string())
+            .unwrap_or_else(|| "-".to_string()),
+        entry
+            .status
+            .map(|s| s.to_string())
+            .unwrap_or_else(|| "-".to_string()),
+        entry
+            .bytes
+            .map(|b| b.to_string())
+            .unwrap_or_else(|| "-".to_string()),
+        entry.duration.as_millis(),
+        entry
+            .error
+            .as_ref()
+            .map(|e| format!(" error={}", e))
+            .unwrap_or_default(),
+    );
+
+    if entry.error.is_some() {
+        log::warn!("{}", line);
+    } else if is_verbose() {
+        log::info!("{}", line);
+    } else {
+        log::debug!("{}", line);
+    }
+
+    if let Ok(log_path) = LOG_FILE.lock() {
+        if let Some(ref path) = *log_path {
+            if let Err(e) = write_to_log_file(path, &line) {
+                eprintln!("Failed to write to access log file: {}", e);
+            }
+        }
+    }
+}
+
+fn write_to_log_file(log_file: &Path, line: &str) -> io::Result<()> {
+    let needs_rotation = if log_file.exists() {
+        std::fs::metadata(log_file)?.len() >= MAX_LOG_FILE_SIZE
+    } else {
+        false
+    };
+
+    if needs_rotation {
+        rotate_log_file(log_file)?;
+    }
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(log_file)?;
+    writeln!(file, "{}", line)?;
+    file.flush()
+}
+
+fn rotate_log_file(log_file: &Path) -> io::Result<()> {
+    for i in (1..=MAX_LOG_BACKUP_COUNT).rev() {
+        let old_file = log_file.with_extension(format!("log.{}", i));
+        let new_file = log_file.with_extension(format!("log.{}", i + 1));
+
+        if old_file.exists() {
+            if i >= MAX_LOG_BACKUP_COUNT {
+                let _ = std::fs::remove_file(&old_file);
+            } else {
+                std::fs::rename(&old_file, &new_file)?;
+            }
+        }
+    }
+
+    if log_file.exists() {
+        std::fs::rename(log_file, log_file.with_extension("log.1"))?;
+    }
+
+    Ok(())
+}
+
+/// Returns the last `limit` lines of the access log, oldest first, for
+/// display in a debug panel.
+pub fn recent_lines(limit: usize) -> Vec<String> {
+    let Ok(log_path) = LOG_FILE.lock() else {
+        return Vec::new();
+    };
+    let Some(path) = log_path.as_ref() else {
+        return Vec::new();
+    };
+    let Ok(file) = std::fs::File::open(path) else {
+        return Vec::new();
+    };
+
+    let lines: Vec<String> = io::BufReader::new(file)
+        .lines()
+        .filter_map(|l| l.ok())
+        .collect();
+    let skip = lines.len().saturating_sub(limit);
+    lines.into_iter().skip(skip).collect()
+}
@@ -0,0 +1,186 @@
+// Typed HTTP client for the Flask/Python sidecar, so commands stop
+// hand-building `http://localhost:{port}/...` strings and stringifying
+// errors.
+use crate::commands::AUTH_TOKEN_HEADER;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use std::fmt;
+use std::time::Duration;
+use url::Url;
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Debug)]
+pub enum ClientError {
+    Io(std::io::Error),
+    Http(reqwest::Error),
+    Url(url::ParseError),
+    Server { status: u16, message: String },
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::Io(e) => write!(f, "io error: {}", e),
+            ClientError::Http(e) => write!(f, "http error: {}", e),
+            ClientError::Url(e) => write!(f, "invalid url: {}", e),
+            ClientError::Server { status, message } => {
+                write!(f, "server returned {}: {}", status, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl ClientError {
+    /// True when the request failed because it hit its own timeout rather
+    /// than an actual connection/server error. Callers doing long-polling
+    /// (where an elapsed timeout just means "no news yet") use this to avoid
+    /// treating a timed-out wait the same as a real failure.
+    pub fn is_timeout(&self) -> bool {
+        matches!(self, ClientError::Http(e) if e.is_timeout())
+    }
+}
+
+macro_rules! impl_client_error_from {
+    ($variant:ident, $source:ty) => {
+        impl From<$source> for ClientError {
+            fn from(err: $source) -> Self {
+                ClientError::$variant(err)
+            }
+        }
+    };
+}
+
+impl_client_error_from!(Io, std::io::Error);
+impl_client_error_from!(Http, reqwest::Error);
+impl_client_error_from!(Url, url::ParseError);
+
+/// Thin typed wrapper around a `reqwest::Client` pinned to the Flask
+/// sidecar's base URL, so callers deal in paths and types instead of raw
+/// strings and `serde_json::Value`.
+#[derive(Clone)]
+pub struct PythonServerClient {
+    client: reqwest::Client,
+    base_url: Url,
+    token: Option<String>,
+}
+
+impl PythonServerClient {
+    pub fn new(base_url: &str, token: Option<String>) -> Result<Self, ClientError> {
+        Ok(Self {
+            client: reqwest::Client::new(),
+            base_url: Url::parse(base_url)?,
+            token,
+        })
+    }
+
+    /// Builds a request pinned to this client's base URL and auth token
+    /// without sending it, for callers (like the streaming file upload) that
+    /// need to attach a body this client's typed methods can't express.
+    pub(crate) fn request(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+    ) -> Result<reqwest::RequestBuilder, ClientError> {
+        let url = self.base_url.join(path)?;
+        let mut builder = self.client.request(method, url).timeout(DEFAULT_TIMEOUT);
+        if let Some(token) = &self.token {
+            builder = builder.header(AUTH_TOKEN_HEADER, token);
+        }
+        Ok(builder)
+    }
+
+    pub async fn health(&self) -> Result<serde_json::Value, ClientError> {
+        self.get("api/health").await
+    }
+
+    pub async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, ClientError> {
+        let builder = self.request(reqwest::Method::GET, path)?;
+        self.send_logged("GET", path, builder).await
+    }
+
+    /// Like `get`, but overrides the client's `DEFAULT_TIMEOUT` with
+    /// `timeout`. Used by long-poll endpoints that intentionally hold the
+    /// connection open well past the default.
+    pub async fn get_with_timeout<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        timeout: Duration,
+    ) -> Result<T, ClientError> {
+        let builder = self.request(reqwest::Method::GET, path)?.timeout(timeout);
+        self.send_logged("GET", path, builder).await
+    }
+
+    pub async fn post<T: DeserializeOwned, B: Serialize>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<T, ClientError> {
+        let builder = self.request(reqwest::Method::POST, path)?.json(body);
+        self.send_logged("POST", path, builder).await
+    }
+
+    pub async fn delete<T: DeserializeOwned>(&self, path: &str) -> Result<T, ClientError> {
+        let builder = self.request(reqwest::Method::DELETE, path)?;
+        self.send_logged("DELETE", path, builder).await
+    }
+
+    /// Sends an already-built request and records the outcome via
+    /// `crate::access_log`, regardless of whether it succeeds.
+    async fn send_logged<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        path: &str,
+        builder: reqwest::RequestBuilder,
+    ) -> Result<T, ClientError> {
+        let started = std::time::Instant::now();
+        let outcome: Result<(T, u16, Option<u64>), ClientError> = async {
+            let response = builder.send().await?;
+            let status = response.status().as_u16();
+            let bytes = response.content_length();
+            let value = Self::into_typed(response).await?;
+            Ok((value, status, bytes))
+        }
+        .await;
+        let duration = started.elapsed();
+
+        let entry = match &outcome {
+            Ok((_, status, bytes)) => crate::access_log::AccessLogEntry {
+                method: method.to_string(),
+                path: path.to_string(),
+                port: self.base_url.port_or_known_default(),
+                status: Some(*status),
+                bytes: *bytes,
+                duration,
+                error: None,
+            },
+            Err(e) => crate::access_log::AccessLogEntry {
+                method: method.to_string(),
+                path: path.to_string(),
+                port: self.base_url.port_or_known_default(),
+                status: None,
+                bytes: None,
+                duration,
+                error: Some(e.to_string()),
+            },
+        };
+        crate::access_log::record(entry);
+
+        outcome.map(|(value, _, _)| value)
+    }
+
+    async fn into_typed<T: DeserializeOwned>(response: reqwest::Response) -> Result<T, ClientError> {
+        let status = response.status();
+        if status.is_success() {
+            Ok(response.json::<T>().await?)
+        } else {
+            let message = response.text().await.unwrap_or_default();
+            Err(ClientError::Server {
+                status: status.as_u16(),
+                message,
+            })
+        }
+    }
+}
@@ -6,11 +6,14 @@ use tauri_plugin_shell::process::CommandChild;
 #[cfg(not(target_os = "windows"))]
 use tauri_plugin_shell::{process::CommandEvent, ShellExt};
 
+use futures::stream::{self, StreamExt};
 use tokio::sync::Mutex as TokioMutex;
+use tokio_util::codec::{BytesCodec, FramedRead};
 
-// kill_tree is no longer used since we only use API shutdown
-// #[cfg(target_os = "windows")]
-// use kill_tree::blocking::kill_tree;
+// Used as the last-resort tier of `stop_python_server_internal` when the
+// Flask process is wedged and won't respond to the API or a graceful signal.
+#[cfg(target_os = "windows")]
+use kill_tree::blocking::kill_tree;
 
 #[derive(Debug, Serialize)]
 pub struct ApiResponse<T> {
@@ -19,10 +22,45 @@ pub struct ApiResponse<T> {
     pub error: Option<String>,
 }
 
+/// Where the Flask/Python backend this app talks to is actually running.
+#[derive(Debug, Clone)]
+pub enum ServerBackend {
+    /// We spawned and own the sidecar process ourselves.
+    Local,
+    /// An already-running server elsewhere (dev machine, container, LAN box);
+    /// we never spawn or force-kill it, just talk to it.
+    Remote { base_url: String },
+}
+
+/// Env var used to opt into remote backend mode instead of spawning the
+/// bundled sidecar. If set to a non-empty URL, `start_python_server` skips
+/// the spawn entirely and points everything at this URL.
+const REMOTE_SERVER_ENV_VAR: &str = "OOC_REMOTE_SERVER_URL";
+
 pub struct PythonServer {
     pub process: Option<CommandChild>,
     pub port: Option<u16>,
     pub pid: Option<u32>,
+    /// Set by an intentional `stop_python_server` call and cleared by the next
+    /// `start_python_server`, so the crash supervisor doesn't try to resurrect
+    /// a server we meant to stop.
+    pub commanded_stop: bool,
+    /// Per-launch shared secret handed to the sidecar via `OOC_AUTH_TOKEN`.
+    /// Required on `/api/health` and `/api/stop` so port scanning can't
+    /// touch an unrelated service that happens to be listening nearby.
+    pub token: Option<String>,
+    /// Whether we're driving a local sidecar or talking to a remote server.
+    pub backend: ServerBackend,
+    /// Whether `stop_python_server` is allowed to shut down a `Remote`
+    /// backend. Defaults to `false` since we don't own that process.
+    pub stop_remote_on_exit: bool,
+    /// Bumped each time `start_python_server` spawns a local child. The wait
+    /// task watching a given child captures the generation it was spawned
+    /// for, so if that child is killed as part of a supervised restart (which
+    /// has already moved on to a new generation by the time the old child's
+    /// exit is observed), it can tell its exit is stale instead of reporting
+    /// it to `handle_unexpected_exit` as a fresh crash.
+    pub generation: u64,
 }
 
 impl PythonServer {
@@ -31,10 +69,147 @@ impl PythonServer {
             process: None,
             port: None,
             pid: None,
+            commanded_stop: false,
+            token: None,
+            backend: ServerBackend::Local,
+            stop_remote_on_exit: false,
+            generation: 0,
         }
     }
 }
 
+/// Best-effort extraction of the port component from a base URL, used only
+/// to keep the existing `u16`-typed `get_flask_port` API working for remote
+/// backends too.
+fn port_from_base_url(base_url: &str) -> Option<u16> {
+    base_url
+        .rsplit(':')
+        .next()
+        .and_then(|tail| tail.trim_end_matches('/').parse::<u16>().ok())
+}
+
+pub(crate) const AUTH_TOKEN_HEADER: &str = "X-OOC-Token";
+
+/// Generates a per-launch shared secret. Not cryptographically secure, just
+/// unique enough that a stray service on the same port range won't guess it.
+fn generate_session_token() -> String {
+    use std::sync::atomic::{AtomicU32, Ordering};
+    static COUNTER: AtomicU32 = AtomicU32::new(0);
+
+    let nanos = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    let pid = std::process::id();
+    let seq = COUNTER.fetch_add(1, Ordering::Relaxed);
+
+    format!("{:x}-{:x}-{:x}", nanos, pid, seq)
+}
+
+// Exponential backoff schedule for the crash supervisor: 500ms, 1s, 2s, 4s, ...
+// capped at 30s, giving up after RESTART_MAX_ATTEMPTS consecutive failures.
+const RESTART_BACKOFF_BASE_MS: u64 = 500;
+const RESTART_BACKOFF_MAX_MS: u64 = 30_000;
+const RESTART_MAX_ATTEMPTS: u32 = 6;
+
+/// This passive crash-detection restart and the active health-poll
+/// supervisor in `supervisor.rs` both drive the same sidecar; this flag makes
+/// sure only one of them runs a restart chain at a time so they can't race
+/// and spawn two replacement processes for the same crash/outage.
+pub(crate) static RESTART_IN_PROGRESS: std::sync::atomic::AtomicBool =
+    std::sync::atomic::AtomicBool::new(false);
+
+/// Called when the Flask child exits (from the child's wait task on Windows,
+/// from the command-event reader task elsewhere). `generation` is the value
+/// `PythonServer::generation` held when this child was spawned; if it no
+/// longer matches, a newer generation has already taken over (e.g. a
+/// supervised restart killed this exact child) and this exit is stale, not a
+/// fresh crash. Otherwise clears the stale process handle/port/pid and, if
+/// the exit wasn't requested via `stop_python_server`, notifies the frontend
+/// and kicks off a supervised restart.
+async fn handle_unexpected_exit(app_handle: AppHandle, generation: u64) {
+    let commanded = if let Some(server_state) = app_handle.try_state::<TokioMutex<PythonServer>>()
+    {
+        let mut server = server_state.lock().await;
+        if server.generation != generation {
+            return;
+        }
+        let was_commanded = server.commanded_stop;
+        server.process = None;
+        server.port = None;
+        server.pid = None;
+        was_commanded
+    } else {
+        true
+    };
+
+    if commanded {
+        return;
+    }
+
+    if RESTART_IN_PROGRESS
+        .compare_exchange(
+            false,
+            true,
+            std::sync::atomic::Ordering::SeqCst,
+            std::sync::atomic::Ordering::SeqCst,
+        )
+        .is_err()
+    {
+        println!("[SUPERVISOR] Restart already in progress elsewhere, skipping");
+        return;
+    }
+
+    println!("[SUPERVISOR] Flask server exited unexpectedly, starting supervised restart");
+    let _ = app_handle.emit("flask-server-down", ());
+    restart_with_backoff(app_handle, 0).await;
+    RESTART_IN_PROGRESS.store(false, std::sync::atomic::Ordering::SeqCst);
+}
+
+/// Attempts to relaunch the Flask sidecar, retrying with exponential backoff
+/// up to `RESTART_MAX_ATTEMPTS` before emitting a terminal failure event.
+/// Callers must hold `RESTART_IN_PROGRESS` before calling this.
+fn restart_with_backoff(
+    app_handle: AppHandle,
+    attempt: u32,
+) -> std::pin::Pin<Box<dyn std::future::Future<Output = ()> + Send>> {
+    Box::pin(async move {
+        if attempt >= RESTART_MAX_ATTEMPTS {
+            let msg = format!(
+                "[SUPERVISOR] Giving up restarting Flask server after {} attempts",
+                attempt
+            );
+            eprintln!("{}", msg);
+            crate::logger::log_error(&msg);
+            let _ = app_handle.emit("flask-server-restart-failed", attempt);
+            return;
+        }
+
+        let backoff_ms =
+            (RESTART_BACKOFF_BASE_MS << attempt.min(6)).min(RESTART_BACKOFF_MAX_MS);
+        println!(
+            "[SUPERVISOR] Restart attempt {} in {}ms",
+            attempt + 1,
+            backoff_ms
+        );
+        tokio::time::sleep(tokio::time::Duration::from_millis(backoff_ms)).await;
+
+        let Some(server_state) = app_handle.try_state::<TokioMutex<PythonServer>>() else {
+            return;
+        };
+
+        match start_python_server(app_handle.clone(), server_state).await {
+            Ok(response) if response.success => {
+                println!("[SUPERVISOR] Flask server restarted successfully");
+                let _ = app_handle.emit("flask-server-restarted", attempt + 1);
+            }
+            _ => {
+                restart_with_backoff(app_handle, attempt + 1).await;
+            }
+        }
+    })
+}
+
 #[tauri::command]
 pub async fn start_python_server(
     app_handle: AppHandle,
@@ -46,6 +221,37 @@ pub async fn start_python_server(
         let _ = stop_python_server(app_handle.clone(), server_state.clone()).await;
     }
 
+    server.commanded_stop = false;
+
+    // Remote backend mode: if the user pointed us at an already-running
+    // server, don't spawn a sidecar at all, just adopt it.
+    if let Ok(remote_url) = std::env::var(REMOTE_SERVER_ENV_VAR) {
+        let remote_url = remote_url.trim().to_string();
+        if !remote_url.is_empty() {
+            println!(
+                "[FLASK_START] Remote backend configured at {}, skipping sidecar spawn",
+                remote_url
+            );
+            server.backend = ServerBackend::Remote {
+                base_url: remote_url.clone(),
+            };
+            server.port = port_from_base_url(&remote_url);
+            server.pid = None;
+            server.token = None;
+            return Ok(ApiResponse {
+                success: true,
+                data: Some(format!("Using remote Python server at {}", remote_url)),
+                error: None,
+            });
+        }
+    }
+    server.backend = ServerBackend::Local;
+    server.generation += 1;
+    let generation = server.generation;
+
+    let session_token = generate_session_token();
+    server.token = Some(session_token.clone());
+
     let db_path = match app_handle.path().app_data_dir() {
         Ok(app_data_dir) => {
             if let Err(e) = std::fs::create_dir_all(&app_data_dir) {
@@ -104,6 +310,7 @@ pub async fn start_python_server(
             cmd.current_dir(&project_root);
             cmd.env("LOG_LEVEL_DEBUG", "true");
             cmd.env("FLASK_ENV", "development");
+            cmd.env("OOC_AUTH_TOKEN", &session_token);
             if let Some(path) = &db_path {
                 cmd.env("DB_PATH", path);
             }
@@ -155,6 +362,7 @@ pub async fn start_python_server(
             };
 
             let mut cmd = Command::new(&exe_path);
+            cmd.env("OOC_AUTH_TOKEN", &session_token);
             if let Some(path) = &db_path {
                 cmd.env("DB_PATH", path);
             }
@@ -206,6 +414,7 @@ pub async fn start_python_server(
         tokio::spawn(async move {
             let mut reader = BufReader::new(stdout);
             let mut line = String::new();
+            let mut current_port: Option<u16> = None;
             while let Ok(n) = reader.read_line(&mut line).await {
                 if n == 0 {
                     break;
@@ -219,6 +428,7 @@ pub async fn start_python_server(
                             .unwrap_or(port_part)
                             .trim();
                         if let Ok(port) = port_str.parse::<u16>() {
+                            current_port = Some(port);
                             if let Some(window) = app_handle_clone.get_webview_window("main") {
                                 let _ = window.emit("flask-port-ready", port);
                             } else {
@@ -235,10 +445,15 @@ pub async fn start_python_server(
                         }
                     }
                 }
+                if !trimmed.is_empty() {
+                    let record = crate::flask_log::ingest(Some(pid), current_port, "stdout", trimmed);
+                    let _ = app_handle_clone.emit("flask-log", &record);
+                }
                 line.clear();
             }
         });
 
+        let app_handle_for_stderr = app_handle.clone();
         tokio::spawn(async move {
             let mut reader = BufReader::new(stderr);
             let mut line = String::new();
@@ -246,9 +461,12 @@ pub async fn start_python_server(
                 if n == 0 {
                     break;
                 }
-                let error_msg = format!("Flask: {}", line.trim());
+                let trimmed = line.trim();
+                let error_msg = format!("Flask: {}", trimmed);
                 eprintln!("{}", error_msg);
                 crate::logger::log_error(&error_msg);
+                let record = crate::flask_log::ingest(Some(pid), None, "stderr", trimmed);
+                let _ = app_handle_for_stderr.emit("flask-log", &record);
                 line.clear();
             }
         });
@@ -257,8 +475,10 @@ pub async fn start_python_server(
         // We need to keep the child alive, so we'll store it in a way that allows us to kill it later
         // For now, we'll use the PID-based approach in stop_python_server_internal
         let mut child_handle = child;
+        let app_handle_for_wait = app_handle.clone();
         tokio::spawn(async move {
             let _ = child_handle.wait().await;
+            handle_unexpected_exit(app_handle_for_wait, generation).await;
         });
 
         return Ok(ApiResponse {
@@ -306,6 +526,7 @@ pub async fn start_python_server(
             // Enable debug logging in development mode (debug_assertions means dev build)
             cmd = cmd.env("LOG_LEVEL_DEBUG", "true");
             cmd = cmd.env("FLASK_ENV", "development");
+            cmd = cmd.env("OOC_AUTH_TOKEN", &session_token);
             if let Some(path) = &db_path {
                 cmd = cmd.env("DB_PATH", path);
             }
@@ -313,6 +534,7 @@ pub async fn start_python_server(
         } else {
             match app_handle.shell().sidecar("flask-api") {
                 Ok(mut cmd) => {
+                    cmd = cmd.env("OOC_AUTH_TOKEN", &session_token);
                     if let Some(path) = &db_path {
                         cmd = cmd.env("DB_PATH", path);
                     }
@@ -338,6 +560,8 @@ pub async fn start_python_server(
             let app_handle_clone = app_handle.clone();
 
             tauri::async_runtime::spawn(async move {
+                let mut current_port: Option<u16> = None;
+                let mut current_pid: Option<u32> = None;
                 while let Some(event) = rx.recv().await {
                     match event {
                         CommandEvent::Stdout(line) => {
@@ -351,6 +575,7 @@ pub async fn start_python_server(
                                         .unwrap_or(port_part)
                                         .trim();
                                     if let Ok(port) = port_str.parse::<u16>() {
+                                        current_port = Some(port);
                                         if let Some(window) =
                                             app_handle_clone.get_webview_window("main")
                                         {
@@ -365,15 +590,14 @@ pub async fn start_python_server(
                                             let mut server = server_state.lock().await;
                                             server.port = Some(port);
 
-                                            // Try to find and store PID by port on Windows
-                                            #[cfg(target_os = "windows")]
-                                            {
-                                                if let Some(pid) = find_pid_by_port(port) {
-                                                    server.pid = Some(pid);
-                                                    println!("[FLASK_START] Found and stored Flask process PID: {} for port: {}", pid, port);
-                                                }
+                                            // Resolve and store the PID now that we know the port
+                                            // (the non-Windows spawn path never got one directly).
+                                            if let Some(pid) = find_pid_by_port(port) {
+                                                server.pid = Some(pid);
+                                                println!("[FLASK_START] Found and stored Flask process PID: {} for port: {}", pid, port);
                                             }
 
+                                            current_pid = server.pid;
                                             drop(server);
                                         }
 
@@ -387,14 +611,36 @@ pub async fn start_python_server(
                                     }
                                 }
                             }
+                            if !trimmed.is_empty() {
+                                let record = crate::flask_log::ingest(
+                                    current_pid,
+                                    current_port,
+                                    "stdout",
+                                    trimmed,
+                                );
+                                let _ = app_handle_clone.emit("flask-log", &record);
+                            }
                         }
                         // Flask will send the further log from here.
                         CommandEvent::Stderr(line) => {
-                            let error_msg = format!("Flask: {}", String::from_utf8_lossy(&line));
+                            let line_str = String::from_utf8_lossy(&line);
+                            let trimmed = line_str.trim();
+                            let error_msg = format!("Flask: {}", trimmed);
                             eprintln!("{}", error_msg);
                             crate::logger::log_error(&error_msg);
+                            let record = crate::flask_log::ingest(
+                                current_pid,
+                                current_port,
+                                "stderr",
+                                trimmed,
+                            );
+                            let _ = app_handle_clone.emit("flask-log", &record);
                         }
                         CommandEvent::Terminated(_) => {
+                            let app_handle_for_exit = app_handle_clone.clone();
+                            tokio::spawn(async move {
+                                handle_unexpected_exit(app_handle_for_exit, generation).await;
+                            });
                             break;
                         }
                         _ => {}
@@ -417,6 +663,61 @@ pub async fn start_python_server(
     }
 }
 
+/// Resolves the base URL to reach the Flask backend, given its current
+/// backend kind and cached port. Returns `None` if we don't know a port yet.
+pub(crate) fn server_base_url(server: &PythonServer) -> Option<String> {
+    match &server.backend {
+        ServerBackend::Remote { base_url } => Some(base_url.trim_end_matches('/').to_string()),
+        ServerBackend::Local => server.port.map(|port| format!("http://localhost:{}", port)),
+    }
+}
+
+const PORT_FILE_POLL_ATTEMPTS: u32 = 10;
+const PORT_FILE_POLL_INTERVAL_MS: u64 = 300;
+
+/// Waits for the sidecar to publish `port.txt`, validates the parsed port,
+/// and confirms it's actually serving before caching it into `PythonServer`.
+/// This replaces the old pattern of silently assuming port 5000 when the
+/// file hasn't been written yet, which raced against a freshly-spawned
+/// server.
+async fn resolve_port(app_handle: &AppHandle) -> Option<u16> {
+    let port_file = app_handle.path().app_data_dir().ok()?.join("port.txt");
+    let client = reqwest::Client::new();
+    let token = match app_handle.try_state::<TokioMutex<PythonServer>>() {
+        Some(server_state) => server_state.lock().await.token.clone(),
+        None => None,
+    };
+
+    for _ in 0..PORT_FILE_POLL_ATTEMPTS {
+        if let Ok(port_str) = std::fs::read_to_string(&port_file) {
+            if let Ok(port) = port_str.trim().parse::<u16>() {
+                let mut request = client
+                    .get(format!("http://localhost:{}/api/health", port))
+                    .timeout(std::time::Duration::from_millis(500));
+                if let Some(token) = &token {
+                    request = request.header(AUTH_TOKEN_HEADER, token);
+                }
+                let is_serving = request
+                    .send()
+                    .await
+                    .map(|response| response.status().is_success())
+                    .unwrap_or(false);
+
+                if is_serving {
+                    if let Some(server_state) = app_handle.try_state::<TokioMutex<PythonServer>>()
+                    {
+                        let mut server = server_state.lock().await;
+                        server.port = Some(port);
+                    }
+                    return Some(port);
+                }
+            }
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(PORT_FILE_POLL_INTERVAL_MS)).await;
+    }
+    None
+}
+
 #[tauri::command]
 pub async fn get_flask_port(
     app_handle: AppHandle,
@@ -443,22 +744,59 @@ pub async fn get_flask_port(
         });
     }
 
+    let expected_token = server.token.clone();
+    let is_remote = matches!(server.backend, ServerBackend::Remote { .. });
     drop(server);
 
+    if is_remote {
+        // Remote backends don't get discovered by scanning ports; if we
+        // don't already have one cached above, there's nothing to find.
+        return Ok(ApiResponse {
+            success: false,
+            data: None,
+            error: Some("Remote backend configured but not reachable yet".to_string()),
+        });
+    }
+
+    let Some(expected_token) = expected_token else {
+        return Ok(ApiResponse {
+            success: false,
+            data: None,
+            error: Some("No active launch token, server was not started by this app".to_string()),
+        });
+    };
+
     let client = reqwest::Client::new();
     let mut tasks = Vec::new();
     for port in 5000..=5100 {
         let client_clone = client.clone();
+        let token_clone = expected_token.clone();
         let task = tokio::spawn(async move {
             match client_clone
                 .get(format!("http://localhost:{}/api/health", port))
+                .header(AUTH_TOKEN_HEADER, &token_clone)
                 .timeout(std::time::Duration::from_millis(200))
                 .send()
                 .await
             {
                 Ok(response) => {
                     if response.status().is_success() {
-                        return Some(port);
+                        // Prefer an exact token echo if the backend provides
+                        // one, but fall back to a status-only match (like
+                        // `check_python_server_status`/`resolve_port`/the
+                        // health supervisor already do) when it doesn't, so
+                        // discovery doesn't silently break against a backend
+                        // that hasn't been updated to echo the token yet.
+                        match response.json::<serde_json::Value>().await {
+                            Ok(body) => match body.get("token").and_then(|t| t.as_str()) {
+                                Some(echoed) if echoed == token_clone.as_str() => {
+                                    return Some(port);
+                                }
+                                Some(_) => {}
+                                None => return Some(port),
+                            },
+                            Err(_) => return Some(port),
+                        }
                     }
                 }
                 Err(_) => {}
@@ -490,26 +828,21 @@ pub async fn get_flask_port(
     })
 }
 
-// Helper function to find PID by port on Windows
-// Currently not used since we only use API shutdown, but kept for potential future use
-#[cfg(target_os = "windows")]
-#[allow(dead_code)]
+/// Finds the PID of the process listening on `port`, on Windows, macOS, and
+/// Linux alike. Queries the OS socket table directly via `netstat2` instead
+/// of shelling out to `netstat` and scraping its output.
 fn find_pid_by_port(port: u16) -> Option<u32> {
-    use std::process::Command;
+    use netstat2::{get_sockets_info, AddressFamilyFlags, ProtocolFlags, ProtocolSocketInfo};
 
-    // Use netstat to find process using the port
-    // netstat -ano | findstr :PORT
-    let output = Command::new("netstat").args(&["-ano"]).output().ok()?;
+    let af_flags = AddressFamilyFlags::IPV4 | AddressFamilyFlags::IPV6;
+    let proto_flags = ProtocolFlags::TCP;
+    let sockets_info = get_sockets_info(af_flags, proto_flags).ok()?;
 
-    let output_str = String::from_utf8_lossy(&output.stdout);
-    let port_str = format!(":{}", port);
-
-    for line in output_str.lines() {
-        if line.contains(&port_str) && line.contains("LISTENING") {
-            // Extract PID (last number in the line)
-            if let Some(pid_part) = line.split_whitespace().last() {
-                if let Ok(pid) = pid_part.parse::<u32>() {
-                    return Some(pid);
+    for socket in sockets_info {
+        if let ProtocolSocketInfo::Tcp(tcp_info) = socket.protocol_socket_info {
+            if tcp_info.local_port == port {
+                if let Some(pid) = socket.associated_pids.first() {
+                    return Some(*pid);
                 }
             }
         }
@@ -517,72 +850,227 @@ fn find_pid_by_port(port: u16) -> Option<u32> {
     None
 }
 
+/// Which tier of `stop_python_server_internal` actually brought the server
+/// down, surfaced back to the caller via `ApiResponse`.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub enum ShutdownTier {
+    /// `/api/stop` succeeded and the server confirmed it.
+    GracefulApi,
+    /// `/api/stop` was unreachable/failed, but a health poll confirmed exit.
+    HealthPollConfirmed,
+    /// A graceful OS-level signal (SIGTERM / terminate) brought it down.
+    GracefulSignal,
+    /// Nothing else worked; the server was force-killed (its whole process
+    /// tree on Windows; on other platforms the server process itself, whose
+    /// own children are not signalled).
+    ForcefulTreeKill,
+    /// Every tier available was tried and the server still answered health
+    /// checks afterwards (a remote backend, or a local one with no known pid).
+    StillRunning,
+    /// Nothing was torn down: no port was known, or the backend is a remote
+    /// server that `stop_remote_on_exit` says to leave running.
+    NoOp,
+}
+
+const HEALTH_POLL_ATTEMPTS: u32 = 10;
+const HEALTH_POLL_INTERVAL_MS: u64 = 300;
+
+/// Polls `/api/health`, returning `true` once the server stops responding
+/// (i.e. it has exited) or `false` if it's still answering after the budget.
+async fn wait_for_port_to_go_quiet(health_base_url: &str, token: Option<&str>) -> bool {
+    let client = reqwest::Client::new();
+    for _ in 0..HEALTH_POLL_ATTEMPTS {
+        let mut request = client
+            .get(format!("{}/api/health", health_base_url))
+            .timeout(std::time::Duration::from_millis(300));
+        if let Some(token) = token {
+            request = request.header(AUTH_TOKEN_HEADER, token);
+        }
+        match request.send().await {
+            Ok(response) if response.status().is_success() => {
+                tokio::time::sleep(std::time::Duration::from_millis(HEALTH_POLL_INTERVAL_MS))
+                    .await;
+            }
+            _ => return true,
+        }
+    }
+    false
+}
+
 pub async fn stop_python_server_internal(
     _app_handle: &AppHandle,
     server_state: &TokioMutex<PythonServer>,
-) -> Result<(), String> {
+) -> Result<ShutdownTier, String> {
     println!("[FLASK_STOP] Starting Flask server stop procedure");
     let mut server = server_state.lock().await;
+    server.commanded_stop = true;
+
+    if let ServerBackend::Remote { base_url } = &server.backend {
+        if !server.stop_remote_on_exit {
+            println!(
+                "[FLASK_STOP] Remote backend at {}, leaving it running (stop_remote_on_exit=false)",
+                base_url
+            );
+            server.port = None;
+            server.pid = None;
+            server.token = None;
+            return Ok(ShutdownTier::NoOp);
+        }
+        println!(
+            "[FLASK_STOP] Remote backend at {}, stop_remote_on_exit=true, calling its /api/stop",
+            base_url
+        );
+    }
 
     let port = server.port;
-    let _process = server.process.take(); // Take ownership but don't use it unless API fails
-    let _stored_pid = server.pid;
+    let token = server.token.clone();
+    let process = server.process.take();
+    let stored_pid = server.pid;
+    let is_remote = matches!(server.backend, ServerBackend::Remote { .. });
+    let remote_base_url = match &server.backend {
+        ServerBackend::Remote { base_url } => Some(base_url.clone()),
+        ServerBackend::Local => None,
+    };
 
-    if let Some(port_val) = port {
+    let tier = if let Some(port_val) = port {
         println!("[FLASK_STOP] Current Flask port: {}", port_val);
 
-        // Use API to gracefully shutdown the server
+        // Tier 1: ask the server to shut itself down via the API.
         let client = reqwest::Client::new();
-        let shutdown_url = format!("http://localhost:{}/api/stop", port_val);
+        let shutdown_url = match &remote_base_url {
+            Some(base_url) => format!("{}/api/stop", base_url.trim_end_matches('/')),
+            None => format!("http://localhost:{}/api/stop", port_val),
+        };
 
-        println!("[FLASK_STOP] Attempting graceful shutdown via API...");
-        match client
+        println!("[FLASK_STOP] Tier 1: attempting graceful shutdown via API...");
+        let mut request = client
             .post(&shutdown_url)
-            .timeout(std::time::Duration::from_secs(10))
-            .send()
-            .await
-        {
+            .timeout(std::time::Duration::from_secs(3));
+        if let Some(token) = &token {
+            request = request.header(AUTH_TOKEN_HEADER, token);
+        }
+        let api_call_succeeded = match request.send().await {
+            Ok(response) if response.status().is_success() => {
+                println!("[FLASK_STOP] Tier 1: API accepted the shutdown request");
+                true
+            }
             Ok(response) => {
-                if response.status().is_success() {
-                    // Try to read the response body to get the message
-                    match response.json::<serde_json::Value>().await {
-                        Ok(json) => {
-                            if let Some(message) = json.get("message").and_then(|m| m.as_str()) {
-                                println!("[FLASK_STOP] Server response: {}", message);
-                            }
-                        }
-                        Err(_) => {
-                            // Response might not be JSON, that's okay
-                        }
-                    }
-                    println!("[FLASK_STOP] Graceful shutdown API call successful, server has been shut down");
-                } else {
-                    println!(
-                        "[FLASK_STOP] API returned status {}, but continuing shutdown",
-                        response.status()
-                    );
-                }
+                println!(
+                    "[FLASK_STOP] Tier 1: API returned status {}, continuing shutdown",
+                    response.status()
+                );
+                false
             }
             Err(e) => {
-                // If API call fails, the server might already be down or unreachable
-                // This is expected if the server has already shut down
                 println!(
-                    "[FLASK_STOP] API call failed (server may have already shut down): {}",
+                    "[FLASK_STOP] Tier 1: API call failed (server may already be down): {}",
                     e
                 );
+                false
+            }
+        };
+
+        let health_base_url = match &remote_base_url {
+            Some(base_url) => base_url.trim_end_matches('/').to_string(),
+            None => format!("http://localhost:{}", port_val),
+        };
+
+        // Tier 2: confirm the process actually exited by polling health.
+        println!("[FLASK_STOP] Tier 2: polling health endpoint to confirm exit...");
+        if wait_for_port_to_go_quiet(&health_base_url, token.as_deref()).await {
+            if api_call_succeeded {
+                ShutdownTier::GracefulApi
+            } else {
+                ShutdownTier::HealthPollConfirmed
+            }
+        } else if is_remote {
+            // We don't own a remote server's process, so there's no pid to
+            // signal or tree to kill; the best we can do already happened.
+            println!("[FLASK_STOP] Remote backend still alive after API call, nothing more we can do");
+            ShutdownTier::StillRunning
+        } else if let Some(pid) = stored_pid.or_else(|| find_pid_by_port(port_val)) {
+            // Tier 3: server is wedged, send a graceful OS-level signal.
+            // Fall back to a fresh netstat2 lookup if we never recorded a pid.
+            println!(
+                "[FLASK_STOP] Tier 3: server still alive, sending graceful signal to pid {}",
+                pid
+            );
+            if send_graceful_signal(pid) && wait_for_port_to_go_quiet(&health_base_url, token.as_deref()).await
+            {
+                ShutdownTier::GracefulSignal
+            } else {
+                // Tier 4: last resort, kill the whole process tree.
+                println!(
+                    "[FLASK_STOP] Tier 4: graceful signal failed, force-killing process tree for pid {}",
+                    pid
+                );
+                force_kill_process_tree(pid, process);
+                ShutdownTier::ForcefulTreeKill
             }
+        } else {
+            println!("[FLASK_STOP] Tier 3/4: server still alive but no pid recorded, giving up gracefully");
+            ShutdownTier::StillRunning
         }
     } else {
         println!("[FLASK_STOP] No port information available, cannot call shutdown API");
-    }
+        ShutdownTier::NoOp
+    };
 
-    // Clear port, process, and PID state
+    // Clear port, process, PID, and token state
     server.port = None;
     server.pid = None;
+    server.token = None;
     println!("[FLASK_STOP] Cleared Flask port and PID from server state");
 
-    println!("[FLASK_STOP] Flask server stop procedure completed");
-    Ok(())
+    println!(
+        "[FLASK_STOP] Flask server stop procedure completed via tier: {:?}",
+        tier
+    );
+    Ok(tier)
+}
+
+/// Sends SIGTERM to `pid` on Unix. On Windows nothing is sent and this
+/// returns `false`, so the caller goes straight to the tree-kill tier.
+#[cfg(not(target_os = "windows"))]
+fn send_graceful_signal(pid: u32) -> bool {
+    // SAFETY: `kill(2)` with SIGTERM is a standard, non-destructive request
+    // for the target process to shut itself down; pid is a plain integer.
+    unsafe { libc::kill(pid as libc::pid_t, libc::SIGTERM) == 0 }
+}
+
+#[cfg(target_os = "windows")]
+fn send_graceful_signal(pid: u32) -> bool {
+    // Windows has no SIGTERM equivalent without a console control handler
+    // attached to the target; fall straight through to the tree-kill tier.
+    let _ = pid;
+    false
+}
+
+/// Last-resort teardown: kills the whole process tree rooted at `pid`.
+#[cfg(target_os = "windows")]
+fn force_kill_process_tree(pid: u32, _process: Option<CommandChild>) {
+    if let Err(e) = kill_tree(pid) {
+        eprintln!("[FLASK_STOP] Tier 4: kill_tree failed for pid {}: {}", pid, e);
+    }
+}
+
+/// Off Windows, kills the stored child handle, or sends SIGKILL to `pid` when
+/// there is no handle; child processes of the server are not signalled.
+#[cfg(not(target_os = "windows"))]
+fn force_kill_process_tree(pid: u32, process: Option<CommandChild>) {
+    if let Some(child) = process {
+        if let Err(e) = child.kill() {
+            eprintln!("[FLASK_STOP] Tier 4: failed to kill pid {}: {}", pid, e);
+        }
+    } else {
+        // SAFETY: `kill(2)` on a plain integer pid, as in `send_graceful_signal`.
+        if unsafe { libc::kill(pid as libc::pid_t, libc::SIGKILL) } != 0 {
+            eprintln!(
+                "[FLASK_STOP] Tier 4: no CommandChild handle and SIGKILL failed for pid {}",
+                pid
+            );
+        }
+    }
 }
 
 #[tauri::command]
@@ -592,11 +1080,11 @@ pub async fn stop_python_server(
 ) -> Result<ApiResponse<String>, String> {
     println!("[FLASK_STOP] stop_python_server command invoked");
     match stop_python_server_internal(&app_handle, &server_state).await {
-        Ok(_) => {
+        Ok(tier) => {
             println!("[FLASK_STOP] stop_python_server command completed successfully");
             Ok(ApiResponse {
                 success: true,
-                data: Some("Python server stopped".to_string()),
+                data: Some(format!("Python server stopped ({:?})", tier)),
                 error: None,
             })
         }
@@ -613,6 +1101,213 @@ pub async fn stop_python_server(
     }
 }
 
+#[tauri::command]
+pub async fn get_supervisor_state(
+    supervisor: State<'_, crate::supervisor::Supervisor>,
+) -> Result<ApiResponse<crate::supervisor::SupervisorState>, String> {
+    Ok(ApiResponse {
+        success: true,
+        data: Some(supervisor.snapshot().await),
+        error: None,
+    })
+}
+
+/// Same data as `get_supervisor_state`, under the name the health-monitoring
+/// UI asks for: healthy/unhealthy-since/restarting/restart-count.
+#[tauri::command]
+pub async fn get_server_health(
+    supervisor: State<'_, crate::supervisor::Supervisor>,
+) -> Result<ApiResponse<crate::supervisor::SupervisorState>, String> {
+    Ok(ApiResponse {
+        success: true,
+        data: Some(supervisor.snapshot().await),
+        error: None,
+    })
+}
+
+/// Returns the status of every registered background worker (Flask health
+/// supervisor and anything else registered with the `WorkerManager`).
+#[tauri::command]
+pub async fn list_workers(
+    manager: State<'_, crate::worker::WorkerManager>,
+) -> Result<ApiResponse<Vec<crate::worker::WorkerStatus>>, String> {
+    Ok(ApiResponse {
+        success: true,
+        data: Some(manager.list_workers().await),
+        error: None,
+    })
+}
+
+/// Sends a start/pause/cancel command to a named background worker.
+#[tauri::command]
+pub async fn control_worker(
+    manager: State<'_, crate::worker::WorkerManager>,
+    name: String,
+    action: crate::worker::WorkerControl,
+) -> Result<ApiResponse<bool>, String> {
+    let found = manager.control_worker(&name, action).await;
+    Ok(ApiResponse {
+        success: found,
+        data: Some(found),
+        error: if found {
+            None
+        } else {
+            Some(format!("no running worker registered under '{}'", name))
+        },
+    })
+}
+
+#[tauri::command]
+pub async fn get_recent_logs(
+    limit: Option<usize>,
+) -> Result<ApiResponse<Vec<crate::flask_log::LogRecord>>, String> {
+    let records = crate::flask_log::recent(limit.unwrap_or(100));
+    Ok(ApiResponse {
+        success: true,
+        data: Some(records),
+        error: None,
+    })
+}
+
+/// Toggles whether access-log lines are also surfaced at `info` level (they
+/// are always written to `access.log` regardless).
+#[tauri::command]
+pub async fn set_access_log_verbose(verbose: bool) -> Result<ApiResponse<bool>, String> {
+    crate::access_log::set_verbose(verbose);
+    Ok(ApiResponse {
+        success: true,
+        data: Some(verbose),
+        error: None,
+    })
+}
+
+/// Returns the last `limit` lines of the Python-server access log for
+/// display in a debug panel.
+#[tauri::command]
+pub async fn get_access_log(limit: Option<usize>) -> Result<ApiResponse<Vec<String>>, String> {
+    Ok(ApiResponse {
+        success: true,
+        data: Some(crate::access_log::recent_lines(limit.unwrap_or(200))),
+        error: None,
+    })
+}
+
+/// Starts a long-running job on the Python backend and subscribes to its
+/// progress, forwarding `job-event`/`job-complete`/`job-error` Tauri events
+/// until it finishes. Returns the job id the backend assigned.
+#[tauri::command]
+pub async fn start_job(
+    app_handle: AppHandle,
+    server_state: State<'_, TokioMutex<PythonServer>>,
+    endpoint: String,
+    payload: serde_json::Value,
+) -> Result<ApiResponse<String>, String> {
+    let server = server_state.lock().await;
+    let token = server.token.clone();
+    let Some(base_url) = server_base_url(&server) else {
+        return Ok(ApiResponse {
+            success: false,
+            data: None,
+            error: Some("Python server port is not known yet".to_string()),
+        });
+    };
+    drop(server);
+
+    let client = match crate::client::PythonServerClient::new(&base_url, token) {
+        Ok(client) => client,
+        Err(e) => {
+            return Ok(ApiResponse {
+                success: false,
+                data: None,
+                error: Some(e.to_string()),
+            })
+        }
+    };
+
+    let response: serde_json::Value =
+        match client.post(endpoint.trim_start_matches('/'), &payload).await {
+            Ok(response) => response,
+            Err(e) => {
+                return Ok(ApiResponse {
+                    success: false,
+                    data: None,
+                    error: Some(e.to_string()),
+                })
+            }
+        };
+
+    let Some(job_id) = response
+        .get("job_id")
+        .and_then(|v| v.as_str())
+        .map(|s| s.to_string())
+    else {
+        return Ok(ApiResponse {
+            success: false,
+            data: None,
+            error: Some("server response did not include a job_id".to_string()),
+        });
+    };
+
+    crate::jobs::spawn_subscription(app_handle, job_id.clone());
+
+    Ok(ApiResponse {
+        success: true,
+        data: Some(job_id),
+        error: None,
+    })
+}
+
+/// Cancels a job's background event subscription and asks the backend to
+/// stop it via `DELETE /api/jobs/{id}`.
+#[tauri::command]
+pub async fn cancel_job(
+    app_handle: AppHandle,
+    server_state: State<'_, TokioMutex<PythonServer>>,
+    job_id: String,
+) -> Result<ApiResponse<()>, String> {
+    if let Some(registry) = app_handle.try_state::<crate::jobs::JobRegistry>() {
+        registry.cancel(&job_id).await;
+    }
+
+    let server = server_state.lock().await;
+    let token = server.token.clone();
+    let Some(base_url) = server_base_url(&server) else {
+        return Ok(ApiResponse {
+            success: false,
+            data: None,
+            error: Some("Python server port is not known yet".to_string()),
+        });
+    };
+    drop(server);
+
+    let client = match crate::client::PythonServerClient::new(&base_url, token) {
+        Ok(client) => client,
+        Err(e) => {
+            return Ok(ApiResponse {
+                success: false,
+                data: None,
+                error: Some(e.to_string()),
+            })
+        }
+    };
+
+    match client
+        .delete::<serde_json::Value>(&format!("api/jobs/{}", job_id))
+        .await
+    {
+        Ok(_) => Ok(ApiResponse {
+            success: true,
+            data: Some(()),
+            error: None,
+        }),
+        Err(e) => Ok(ApiResponse {
+            success: false,
+            data: None,
+            error: Some(e.to_string()),
+        }),
+    }
+}
+
 #[tauri::command]
 pub async fn get_database_path(app_handle: AppHandle) -> Result<ApiResponse<String>, String> {
     match app_handle.path().app_data_dir() {
@@ -648,50 +1343,245 @@ pub async fn check_python_server_status(
     server_state: State<'_, TokioMutex<PythonServer>>,
 ) -> Result<ApiResponse<bool>, String> {
     let server = server_state.lock().await;
+    let cached_port = server.port;
+    let token = server.token.clone();
+    let remote_base_url = match &server.backend {
+        ServerBackend::Remote { base_url } => Some(base_url.trim_end_matches('/').to_string()),
+        ServerBackend::Local => None,
+    };
+    drop(server);
 
-    let port = server.port.unwrap_or_else(|| {
-        if let Some(port_file) = app_handle
-            .path()
-            .app_data_dir()
-            .ok()
-            .map(|dir| dir.join("port.txt"))
-        {
-            if let Ok(port_str) = std::fs::read_to_string(&port_file) {
-                if let Ok(port) = port_str.trim().parse::<u16>() {
-                    return port;
+    let base_url = if let Some(remote_base_url) = remote_base_url {
+        remote_base_url
+    } else {
+        let port = match cached_port {
+            Some(port) => port,
+            None => match resolve_port(&app_handle).await {
+                Some(port) => port,
+                None => {
+                    return Ok(ApiResponse {
+                        success: false,
+                        data: Some(false),
+                        error: Some(
+                            "Could not resolve Flask port, server may not be started".to_string(),
+                        ),
+                    });
                 }
-            }
+            },
+        };
+        format!("http://localhost:{}", port)
+    };
+    let client = match crate::client::PythonServerClient::new(&base_url, token) {
+        Ok(client) => client,
+        Err(e) => {
+            return Ok(ApiResponse {
+                success: false,
+                data: Some(false),
+                error: Some(format!("Invalid server URL: {}", e)),
+            });
         }
-        5000 // 默认端口
-    });
+    };
 
-    let client = reqwest::Client::new();
+    match client.health().await {
+        Ok(_) => Ok(ApiResponse {
+            success: true,
+            data: Some(true),
+            error: None,
+        }),
+        Err(crate::client::ClientError::Server { status, .. }) => Ok(ApiResponse {
+            success: false,
+            data: Some(false),
+            error: Some(format!("Server response error ({})", status)),
+        }),
+        Err(_) => Ok(ApiResponse {
+            success: false,
+            data: Some(false),
+            error: Some("Cannot connect to server".to_string()),
+        }),
+    }
+}
 
-    match client
-        .get(format!("http://localhost:{}/api/health", port))
-        .timeout(std::time::Duration::from_secs(3))
-        .send()
-        .await
-    {
+// Large uploads can take far longer than the client's default 10s timeout to
+// finish streaming, so the request is given its own generous budget instead.
+const UPLOAD_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(600);
+
+/// Streams a local file to the Python backend as a multipart upload without
+/// buffering the whole thing in memory, so multi-gigabyte inputs upload with
+/// constant memory.
+#[tauri::command]
+pub async fn upload_file(
+    server_state: State<'_, TokioMutex<PythonServer>>,
+    path: String,
+    endpoint: String,
+) -> Result<ApiResponse<serde_json::Value>, String> {
+    let server = server_state.lock().await;
+    let token = server.token.clone();
+    let Some(base_url) = server_base_url(&server) else {
+        return Ok(ApiResponse {
+            success: false,
+            data: None,
+            error: Some("Python server port is not known yet".to_string()),
+        });
+    };
+    drop(server);
+
+    let file = match tokio::fs::File::open(&path).await {
+        Ok(file) => file,
+        Err(e) => {
+            return Ok(ApiResponse {
+                success: false,
+                data: None,
+                error: Some(format!("Failed to open file {}: {}", path, e)),
+            });
+        }
+    };
+
+    let filename = std::path::Path::new(&path)
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| "upload".to_string());
+
+    let stream = FramedRead::new(file, BytesCodec::new());
+    let body = reqwest::Body::wrap_stream(stream);
+    let part = reqwest::multipart::Part::stream(body).file_name(filename);
+    let form = reqwest::multipart::Form::new().part("file", part);
+
+    let client = match crate::client::PythonServerClient::new(&base_url, token) {
+        Ok(client) => client,
+        Err(e) => {
+            return Ok(ApiResponse {
+                success: false,
+                data: None,
+                error: Some(format!("Invalid server URL: {}", e)),
+            });
+        }
+    };
+
+    let request = match client.request(reqwest::Method::POST, endpoint.trim_start_matches('/')) {
+        Ok(builder) => builder.multipart(form).timeout(UPLOAD_TIMEOUT),
+        Err(e) => {
+            return Ok(ApiResponse {
+                success: false,
+                data: None,
+                error: Some(e.to_string()),
+            });
+        }
+    };
+
+    match request.send().await {
         Ok(response) => {
-            if response.status().is_success() {
+            let status = response.status();
+            if status.is_success() {
+                let data = response.json::<serde_json::Value>().await.ok();
                 Ok(ApiResponse {
                     success: true,
-                    data: Some(true),
+                    data,
                     error: None,
                 })
             } else {
                 Ok(ApiResponse {
                     success: false,
-                    data: Some(false),
-                    error: Some("Server response error".to_string()),
+                    data: None,
+                    error: Some(format!("Upload failed with status {}", status)),
                 })
             }
         }
-        Err(_) => Ok(ApiResponse {
+        Err(e) => Ok(ApiResponse {
             success: false,
-            data: Some(false),
-            error: Some("Cannot connect to server".to_string()),
+            data: None,
+            error: Some(format!("Upload request failed: {}", e)),
         }),
     }
 }
+
+const DEFAULT_BATCH_CONCURRENCY: usize = 16;
+
+#[derive(Debug, Serialize)]
+pub struct BatchItemResult {
+    pub index: usize,
+    pub success: bool,
+    pub data: Option<serde_json::Value>,
+    pub error: Option<String>,
+}
+
+/// Pushes many items through the Python backend in parallel, bounded by
+/// `concurrency`, emitting a `batch-progress` event as each one completes so
+/// the frontend can render a progress bar without blocking on the whole
+/// batch.
+#[tauri::command]
+pub async fn batch_process(
+    app_handle: AppHandle,
+    server_state: State<'_, TokioMutex<PythonServer>>,
+    items: Vec<serde_json::Value>,
+    endpoint: String,
+    concurrency: Option<usize>,
+) -> Result<ApiResponse<Vec<BatchItemResult>>, String> {
+    let server = server_state.lock().await;
+    let token = server.token.clone();
+    let Some(base_url) = server_base_url(&server) else {
+        return Ok(ApiResponse {
+            success: false,
+            data: None,
+            error: Some("Python server port is not known yet".to_string()),
+        });
+    };
+    drop(server);
+
+    let client = match crate::client::PythonServerClient::new(&base_url, token) {
+        Ok(client) => client,
+        Err(e) => {
+            return Ok(ApiResponse {
+                success: false,
+                data: None,
+                error: Some(e.to_string()),
+            })
+        }
+    };
+    let endpoint = endpoint.trim_start_matches('/').to_string();
+    let concurrency = concurrency.unwrap_or(DEFAULT_BATCH_CONCURRENCY).max(1);
+    let total = items.len();
+    let completed = std::sync::Arc::new(std::sync::atomic::AtomicUsize::new(0));
+
+    let mut results: Vec<BatchItemResult> = stream::iter(items.into_iter().enumerate())
+        .map(|(index, item)| {
+            let client = client.clone();
+            let endpoint = endpoint.clone();
+            let app_handle = app_handle.clone();
+            let completed = completed.clone();
+            async move {
+                let result = match client.post::<serde_json::Value, _>(&endpoint, &item).await {
+                    Ok(data) => BatchItemResult {
+                        index,
+                        success: true,
+                        data: Some(data),
+                        error: None,
+                    },
+                    Err(e) => BatchItemResult {
+                        index,
+                        success: false,
+                        data: None,
+                        error: Some(e.to_string()),
+                    },
+                };
+
+                let done = completed.fetch_add(1, std::sync::atomic::Ordering::SeqCst) + 1;
+                let _ = app_handle.emit(
+                    "batch-progress",
+                    serde_json::json!({ "completed": done, "total": total }),
+                );
+
+                result
+            }
+        })
+        .buffer_unordered(concurrency)
+        .collect()
+        .await;
+
+    results.sort_by_key(|r| r.index);
+
+    Ok(ApiResponse {
+        success: true,
+        data: Some(results),
+        error: None,
+    })
+}
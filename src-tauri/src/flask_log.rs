@@ -0,0 +1,89 @@
+// Structured log pipeline for the Flask sidecar's stdout/stderr, so the
+// frontend can subscribe to live logs instead of only seeing crashes. Each
+// line is recorded into a bounded ring buffer (exposed via `get_recent_logs`)
+// and emitted as a `tracing` event within a span keyed on pid/port, so
+// local-vs-remote backends and restart generations stay distinguishable in
+// the trace output even though they reuse the same `source`/level tags.
+use serde::Serialize;
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+const RING_BUFFER_CAPACITY: usize = 500;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LogRecord {
+    pub level: String,
+    pub source: String,
+    pub message: String,
+    pub ts: u64,
+    /// PID of the Flask process this line came from, when known. Lets the
+    /// debug console tell lines from a restarted process apart from the one
+    /// it replaced.
+    pub pid: Option<u32>,
+    /// Port the Flask process was serving on when this line was captured.
+    pub port: Option<u16>,
+}
+
+static RING_BUFFER: Mutex<VecDeque<LogRecord>> = Mutex::new(VecDeque::new());
+
+fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+/// Flask's default logger prefixes each line with a level name; fall back to
+/// INFO when nothing matches so stray stdout output isn't dropped.
+fn detect_level(line: &str) -> &'static str {
+    let upper = line.to_uppercase();
+    if upper.contains("CRITICAL") {
+        "CRITICAL"
+    } else if upper.contains("ERROR") {
+        "ERROR"
+    } else if upper.contains("WARNING") || upper.contains("WARN") {
+        "WARN"
+    } else if upper.contains("DEBUG") {
+        "DEBUG"
+    } else {
+        "INFO"
+    }
+}
+
+/// Parses a raw Flask log line, records it into the bounded ring buffer, and
+/// emits it as a `tracing` event within a `pid`/`port`-keyed span.
+pub fn ingest(pid: Option<u32>, port: Option<u16>, source: &str, line: &str) -> LogRecord {
+    let level = detect_level(line);
+    let record = LogRecord {
+        level: level.to_string(),
+        source: source.to_string(),
+        message: line.to_string(),
+        ts: now_secs(),
+        pid,
+        port,
+    };
+
+    tracing::info_span!(
+        "flask",
+        pid = pid.unwrap_or_default(),
+        port = port.unwrap_or_default()
+    )
+    .in_scope(|| {
+        tracing::event!(tracing::Level::INFO, level, source, message = line);
+    });
+
+    let mut buffer = RING_BUFFER.lock().unwrap();
+    if buffer.len() >= RING_BUFFER_CAPACITY {
+        buffer.pop_front();
+    }
+    buffer.push_back(record.clone());
+
+    record
+}
+
+/// Returns the most recent `limit` log records, oldest first.
+pub fn recent(limit: usize) -> Vec<LogRecord> {
+    let buffer = RING_BUFFER.lock().unwrap();
+    let skip = buffer.len().saturating_sub(limit);
+    buffer.iter().skip(skip).cloned().collect()
+}
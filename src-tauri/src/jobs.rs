@@ -0,0 +1,166 @@
+// Long-poll subscription to Python-backend job progress: after a job is
+// started, repeatedly polls `/api/jobs/{id}/events` and forwards each chunk
+// to the frontend as Tauri events, reconnecting with the same backoff the
+// supervisor uses on transient failures, until the job completes, errors,
+// or is cancelled.
+use crate::client::PythonServerClient;
+use crate::commands::{server_base_url, PythonServer};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+use tokio::sync::Mutex as TokioMutex;
+
+const POLL_BACKOFF_BASE_MS: u64 = 500;
+const POLL_BACKOFF_MAX_MS: u64 = 30_000;
+const POLL_MAX_CONSECUTIVE_FAILURES: u32 = 8;
+
+/// Timeout for the events long-poll request itself, well past the client's
+/// `DEFAULT_TIMEOUT` since the backend is expected to hold the connection
+/// open until there's something new to report.
+const EVENTS_LONG_POLL_TIMEOUT: Duration = Duration::from_secs(35);
+/// Delay between iterations after a successful poll that didn't end the job,
+/// so a backend that answers immediately (rather than actually holding the
+/// connection) doesn't get hammered at full speed.
+const POLL_SUCCESS_DELAY_MS: u64 = 250;
+
+/// Tracks cancellation flags for in-flight job subscriptions, keyed by job
+/// id, so `cancel_job` can stop a background poll loop it doesn't otherwise
+/// have a handle to.
+#[derive(Default)]
+pub struct JobRegistry {
+    cancel_flags: TokioMutex<HashMap<String, Arc<AtomicBool>>>,
+}
+
+impl JobRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    async fn register(&self, job_id: &str) -> Arc<AtomicBool> {
+        let flag = Arc::new(AtomicBool::new(false));
+        self.cancel_flags
+            .lock()
+            .await
+            .insert(job_id.to_string(), flag.clone());
+        flag
+    }
+
+    pub(crate) async fn cancel(&self, job_id: &str) -> bool {
+        match self.cancel_flags.lock().await.get(job_id) {
+            Some(flag) => {
+                flag.store(true, Ordering::SeqCst);
+                true
+            }
+            None => false,
+        }
+    }
+
+    async fn clear(&self, job_id: &str) {
+        self.cancel_flags.lock().await.remove(job_id);
+    }
+}
+
+async fn resolve_client(app_handle: &AppHandle, job_id: &str) -> Option<(PythonServerClient, String)> {
+    let server_state = app_handle.try_state::<TokioMutex<PythonServer>>()?;
+    let server = server_state.lock().await;
+    let base_url = server_base_url(&server)?;
+    let token = server.token.clone();
+    drop(server);
+
+    let client = PythonServerClient::new(&base_url, token).ok()?;
+    Some((client, format!("api/jobs/{}/events", job_id)))
+}
+
+/// Spawns the background long-poll loop for a job that has already been
+/// started on the backend.
+pub fn spawn_subscription(app_handle: AppHandle, job_id: String) {
+    tauri::async_runtime::spawn(async move {
+        let Some(registry) = app_handle.try_state::<JobRegistry>() else {
+            return;
+        };
+        let cancelled = registry.register(&job_id).await;
+
+        let mut failures: u32 = 0;
+        loop {
+            if cancelled.load(Ordering::SeqCst) {
+                break;
+            }
+
+            let Some((client, events_path)) = resolve_client(&app_handle, &job_id).await else {
+                let _ = app_handle.emit(
+                    "job-error",
+                    serde_json::json!({ "jobId": job_id, "error": "Python server is not available" }),
+                );
+                break;
+            };
+
+            match client
+                .get_with_timeout::<serde_json::Value>(&events_path, EVENTS_LONG_POLL_TIMEOUT)
+                .await
+            {
+                Ok(payload) => {
+                    failures = 0;
+                    let _ = app_handle.emit(
+                        "job-event",
+                        serde_json::json!({ "jobId": job_id, "data": payload }),
+                    );
+
+                    let status = payload_status(&payload);
+                    if status == Some("completed") {
+                        let _ = app_handle.emit("job-complete", serde_json::json!({ "jobId": job_id }));
+                        break;
+                    } else if status == Some("error") {
+                        let message = payload
+                            .get("error")
+                            .and_then(|e| e.as_str())
+                            .unwrap_or("job reported an error")
+                            .to_string();
+                        let _ = app_handle
+                            .emit("job-error", serde_json::json!({ "jobId": job_id, "error": message }));
+                        break;
+                    }
+
+                    tokio::time::sleep(std::time::Duration::from_millis(POLL_SUCCESS_DELAY_MS)).await;
+                }
+                Err(e) if e.is_timeout() => {
+                    // The long-poll window simply elapsed with nothing new to
+                    // report; reconnect immediately without counting it as a
+                    // failure or backing off, unless we've since been
+                    // cancelled.
+                    if cancelled.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    continue;
+                }
+                Err(e) => {
+                    if cancelled.load(Ordering::SeqCst) {
+                        break;
+                    }
+
+                    failures += 1;
+                    if failures >= POLL_MAX_CONSECUTIVE_FAILURES {
+                        let _ = app_handle.emit(
+                            "job-error",
+                            serde_json::json!({
+                                "jobId": job_id,
+                                "error": format!("subscription failed after {} attempts: {}", failures, e),
+                            }),
+                        );
+                        break;
+                    }
+
+                    let backoff_ms = (POLL_BACKOFF_BASE_MS << failures.min(6)).min(POLL_BACKOFF_MAX_MS);
+                    tokio::time::sleep(std::time::Duration::from_millis(backoff_ms)).await;
+                }
+            }
+        }
+
+        registry.clear(&job_id).await;
+    });
+}
+
+fn payload_status(payload: &serde_json::Value) -> Option<&str> {
+    payload.get("status").and_then(|s| s.as_str())
+}
@@ -1,16 +1,65 @@
 use std::fs::OpenOptions;
 use std::io::{self, Write};
 use std::path::{Path, PathBuf};
+use std::sync::mpsc;
 use std::sync::Mutex;
+use std::time::Duration;
 
 const MAX_LOG_FILE_SIZE: u64 = 10 * 1024 * 1024; // 10MB
 const MAX_LOG_BACKUP_COUNT: usize = 5;
 const MAX_TOTAL_LOG_SIZE: u64 = MAX_LOG_FILE_SIZE * MAX_LOG_BACKUP_COUNT as u64; // ~50MB
+const DEFAULT_MAX_LOG_AGE: Duration = Duration::from_secs(24 * 60 * 60); // 24h
+const CLEANUP_EVERY_N_WRITES: u32 = 10;
 
 static LOG_FILE: Mutex<Option<PathBuf>> = Mutex::new(None);
+static MAX_LOG_AGE: Mutex<Duration> = Mutex::new(DEFAULT_MAX_LOG_AGE);
+static LOG_SENDER: Mutex<Option<mpsc::Sender<LogRecord>>> = Mutex::new(None);
+// Primary dir first, then fallbacks in the order `init_logger` was given
+// them; `ACTIVE_LOG_DIR` is the index currently being written to.
+static LOG_DIRS: Mutex<Vec<PathBuf>> = Mutex::new(Vec::new());
+static ACTIVE_LOG_DIR: Mutex<usize> = Mutex::new(0);
 
-pub fn init_logger(app_data_dir: Option<&Path>) -> io::Result<()> {
-    let log_dir = if let Some(app_dir) = app_data_dir {
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+}
+
+impl LogLevel {
+    fn as_str(self) -> &'static str {
+        match self {
+            LogLevel::Error => "ERROR",
+            LogLevel::Warn => "WARN",
+            LogLevel::Info => "INFO",
+            LogLevel::Debug => "DEBUG",
+        }
+    }
+}
+
+struct LogRecord {
+    level: LogLevel,
+    timestamp: u64,
+    message: String,
+}
+
+/// Initializes logging: starts the background worker thread that owns the
+/// log file under `app_data_dir/logs` (or `./logs` if unset) and runs an
+/// initial cleanup pass. `max_age` overrides how old a rotated log file may
+/// get before `cleanup_old_logs` deletes it, defaulting to 24 hours.
+/// `fallback_dirs` are tried in order if the primary directory is full; logs
+/// there rotate independently of the primary.
+///
+/// `log_error`/`log_warn`/`log_info`/`log_debug` only enqueue a record onto
+/// an `mpsc` channel and return immediately; the worker thread performs the
+/// actual file I/O, batching, rotation, and cleanup off the caller's thread.
+pub fn init_logger(
+    app_data_dir: Option<&Path>,
+    max_age: Option<Duration>,
+    fallback_dirs: Vec<PathBuf>,
+) -> io::Result<()> {
+    let primary_dir = if let Some(app_dir) = app_data_dir {
         let log_dir = app_dir.join("logs");
         std::fs::create_dir_all(&log_dir)?;
         log_dir
@@ -18,34 +67,152 @@ pub fn init_logger(app_data_dir: Option<&Path>) -> io::Result<()> {
         PathBuf::from("logs")
     };
 
-    let log_file = log_dir.join("rust_error.log");
+    let mut dirs = vec![primary_dir];
+    for dir in fallback_dirs {
+        let _ = std::fs::create_dir_all(&dir);
+        dirs.push(dir);
+    }
+
+    *LOG_FILE.lock().unwrap() = Some(dirs[0].join("rust_error.log"));
+    *ACTIVE_LOG_DIR.lock().unwrap() = 0;
+    *LOG_DIRS.lock().unwrap() = dirs;
 
-    // Initialize log file path
-    let mut log_path = LOG_FILE.lock().unwrap();
-    *log_path = Some(log_file);
-    drop(log_path);
+    if let Some(max_age) = max_age {
+        *MAX_LOG_AGE.lock().unwrap() = max_age;
+    }
 
-    // Clean up old logs
-    cleanup_old_logs(&log_dir);
+    let (tx, rx) = mpsc::channel::<LogRecord>();
+    *LOG_SENDER.lock().unwrap() = Some(tx);
+    std::thread::spawn(move || run_log_worker(rx));
 
     Ok(())
 }
 
-pub fn log_error(message: &str) {
-    if let Ok(log_path) = LOG_FILE.lock() {
-        if let Some(ref path) = *log_path {
-            if let Err(e) = write_to_log_file(path, message) {
-                eprintln!("Failed to write to log file: {}", e);
+/// Background worker: runs the initial cleanup pass, then blocks for the next
+/// record and drains whatever else has queued up so a burst of log calls
+/// costs one open and one flush of the log file instead of one per call.
+fn run_log_worker(rx: mpsc::Receiver<LogRecord>) {
+    let mut writes_since_cleanup: u32 = 0;
+
+    for dir in LOG_DIRS.lock().unwrap().clone() {
+        cleanup_old_logs(&dir);
+    }
+
+    while let Ok(first) = rx.recv() {
+        let mut batch = vec![first];
+        while let Ok(record) = rx.try_recv() {
+            batch.push(record);
+        }
+
+        if let Err(e) = write_batch_with_spill(&batch) {
+            eprintln!(
+                "Failed to write to log file (all configured log directories exhausted): {}",
+                e
+            );
+        }
+
+        writes_since_cleanup += batch.len() as u32;
+        if writes_since_cleanup >= CLEANUP_EVERY_N_WRITES {
+            writes_since_cleanup = 0;
+            for dir in LOG_DIRS.lock().unwrap().clone() {
+                cleanup_old_logs(&dir);
             }
         }
     }
 }
 
-fn write_to_log_file(log_file: &Path, message: &str) -> io::Result<()> {
-    // Check if file exists and its size
+/// Writes `batch` to the currently active log directory; if that fails
+/// because the volume is full, tries each configured fallback directory in
+/// turn and, on the first that succeeds, makes it the new active directory.
+fn write_batch_with_spill(batch: &[LogRecord]) -> io::Result<()> {
+    let dirs = LOG_DIRS.lock().unwrap().clone();
+    if dirs.is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::Other,
+            "logger has no configured directories",
+        ));
+    }
+    let start = *ACTIVE_LOG_DIR.lock().unwrap() % dirs.len();
+
+    let mut last_err = None;
+    for offset in 0..dirs.len() {
+        let index = (start + offset) % dirs.len();
+        let log_file = dirs[index].join("rust_error.log");
+
+        match write_batch(&log_file, batch) {
+            Ok(()) => {
+                if index != start {
+                    eprintln!(
+                        "Primary log directory appears full; spilling to {}",
+                        dirs[index].display()
+                    );
+                    *ACTIVE_LOG_DIR.lock().unwrap() = index;
+                    *LOG_FILE.lock().unwrap() = Some(log_file);
+                }
+                return Ok(());
+            }
+            Err(e) if is_out_of_space(&e) => last_err = Some(e),
+            Err(e) => return Err(e),
+        }
+    }
+
+    Err(last_err.unwrap())
+}
+
+fn is_out_of_space(err: &io::Error) -> bool {
+    err.kind() == io::ErrorKind::StorageFull || err.raw_os_error() == Some(28) // ENOSPC
+}
+
+fn enqueue(level: LogLevel, message: String) {
+    let timestamp = now_secs();
+    let record = LogRecord {
+        level,
+        timestamp,
+        message,
+    };
+
+    let sent = LOG_SENDER
+        .lock()
+        .unwrap()
+        .as_ref()
+        .map(|sender| sender.send(record).is_ok())
+        .unwrap_or(false);
+
+    if !sent {
+        eprintln!(
+            "[{}] {}: logger not initialized, dropping message",
+            timestamp,
+            level.as_str()
+        );
+    }
+}
+
+pub fn log_error(message: &str) {
+    enqueue(LogLevel::Error, message.to_string());
+}
+
+pub fn log_warn(message: &str) {
+    enqueue(LogLevel::Warn, message.to_string());
+}
+
+pub fn log_info(message: &str) {
+    enqueue(LogLevel::Info, message.to_string());
+}
+
+pub fn log_debug(message: &str) {
+    enqueue(LogLevel::Debug, message.to_string());
+}
+
+fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+fn write_batch(log_file: &Path, batch: &[LogRecord]) -> io::Result<()> {
     let needs_rotation = if log_file.exists() {
-        let metadata = std::fs::metadata(log_file)?;
-        metadata.len() >= MAX_LOG_FILE_SIZE
+        std::fs::metadata(log_file)?.len() >= MAX_LOG_FILE_SIZE
     } else {
         false
     };
@@ -54,34 +221,22 @@ fn write_to_log_file(log_file: &Path, message: &str) -> io::Result<()> {
         rotate_log_file(log_file)?;
     }
 
-    // Open file in append mode
     let mut file = OpenOptions::new()
         .create(true)
         .append(true)
         .open(log_file)?;
 
-    // Write log entry with timestamp
-    let timestamp = std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .unwrap()
-        .as_secs();
-    writeln!(file, "[{}] ERROR: {}", timestamp, message)?;
-    file.flush()?;
-
-    // Clean up old logs periodically (every 10 writes)
-    if std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .unwrap()
-        .as_secs()
-        % 10
-        == 0
-    {
-        if let Some(log_dir) = log_file.parent() {
-            cleanup_old_logs(log_dir);
-        }
+    for record in batch {
+        writeln!(
+            file,
+            "[{}] {}: {}",
+            record.timestamp,
+            record.level.as_str(),
+            record.message
+        )?;
     }
 
-    Ok(())
+    file.flush()
 }
 
 fn rotate_log_file(log_file: &Path) -> io::Result<()> {
@@ -110,6 +265,10 @@ fn rotate_log_file(log_file: &Path) -> io::Result<()> {
 }
 
 fn cleanup_old_logs(log_dir: &Path) {
+    let active_log_file = LOG_FILE.lock().unwrap().clone();
+    let max_age = *MAX_LOG_AGE.lock().unwrap();
+    let now = std::time::SystemTime::now();
+
     let log_files: Vec<PathBuf> = std::fs::read_dir(log_dir)
         .ok()
         .and_then(|entries| {
@@ -133,6 +292,29 @@ fn cleanup_old_logs(log_dir: &Path) {
         })
         .unwrap_or_default();
 
+    // Age-based expiry: drop rotated logs older than `max_age`, skipping the
+    // active log file and guarding against clock skew (a future mtime).
+    for file in &log_files {
+        if active_log_file.as_deref() == Some(file.as_path()) {
+            continue;
+        }
+
+        let Ok(modified) = std::fs::metadata(file).and_then(|m| m.modified()) else {
+            continue;
+        };
+        let Ok(age) = now.duration_since(modified) else {
+            continue; // mtime is in the future; skip rather than risk deleting a fresh file
+        };
+
+        if age > max_age {
+            let _ = std::fs::remove_file(file);
+        }
+    }
+
+    // Re-scan: the size trim below should only consider files that survived
+    // the age-based pass.
+    let log_files: Vec<PathBuf> = log_files.into_iter().filter(|f| f.exists()).collect();
+
     let total_size: u64 = log_files
         .iter()
         .filter_map(|p| std::fs::metadata(p).ok())
@@ -170,14 +352,28 @@ fn cleanup_old_logs(log_dir: &Path) {
     }
 }
 
-// Macro to log errors
+/// Formats a message, prints it to stderr, and enqueues it onto the logging
+/// worker at the given level, e.g. `rust_log!(error, "failed: {}", e)`.
 #[macro_export]
-macro_rules! rust_log_error {
-    ($($arg:tt)*) => {
-        {
-            let message = format!($($arg)*);
-            eprintln!("{}", message);
-            $crate::logger::log_error(&message);
-        }
-    };
+macro_rules! rust_log {
+    (error, $($arg:tt)*) => {{
+        let message = format!($($arg)*);
+        eprintln!("{}", message);
+        $crate::logger::log_error(&message);
+    }};
+    (warn, $($arg:tt)*) => {{
+        let message = format!($($arg)*);
+        eprintln!("{}", message);
+        $crate::logger::log_warn(&message);
+    }};
+    (info, $($arg:tt)*) => {{
+        let message = format!($($arg)*);
+        eprintln!("{}", message);
+        $crate::logger::log_info(&message);
+    }};
+    (debug, $($arg:tt)*) => {{
+        let message = format!($($arg)*);
+        eprintln!("{}", message);
+        $crate::logger::log_debug(&message);
+    }};
 }
@@ -3,16 +3,27 @@
     windows_subsystem = "windows"
 )]
 
+mod access_log;
+mod client;
 mod commands;
+mod flask_log;
+mod jobs;
 mod logger;
+mod supervisor;
+mod worker;
 
 use commands::{
-    check_python_server_status, get_database_path, get_flask_port, start_python_server,
-    stop_python_server, stop_python_server_internal, PythonServer,
+    batch_process, cancel_job, check_python_server_status, control_worker, get_access_log,
+    get_database_path, get_flask_port, get_recent_logs, get_server_health, get_supervisor_state,
+    list_workers, set_access_log_verbose, start_job, start_python_server, stop_python_server,
+    stop_python_server_internal, upload_file, PythonServer,
 };
+use jobs::JobRegistry;
 use std::sync::atomic::{AtomicBool, Ordering};
+use supervisor::Supervisor;
 use tauri::{Manager, RunEvent};
 use tokio::sync::Mutex as TokioMutex;
+use worker::WorkerManager;
 
 static CLOSING: AtomicBool = AtomicBool::new(false);
 
@@ -22,19 +33,34 @@ fn main() {
         .plugin(tauri_plugin_dialog::init())
         .plugin(tauri_plugin_shell::init())
         .manage(TokioMutex::new(PythonServer::new()))
+        .manage(Supervisor::new())
+        .manage(JobRegistry::new())
+        .manage(WorkerManager::new())
         .invoke_handler(tauri::generate_handler![
             start_python_server,
             stop_python_server,
             check_python_server_status,
             get_database_path,
             get_flask_port,
+            get_recent_logs,
+            get_supervisor_state,
+            upload_file,
+            batch_process,
+            set_access_log_verbose,
+            get_access_log,
+            start_job,
+            cancel_job,
+            get_server_health,
+            list_workers,
+            control_worker,
         ])
         .setup(|app| {
             let app_handle = app.app_handle().clone();
 
             // Initialize logger
             if let Ok(app_data_dir) = app_handle.path().app_data_dir() {
-                let _ = logger::init_logger(Some(&app_data_dir));
+                let _ = logger::init_logger(Some(&app_data_dir), None, Vec::new());
+                let _ = access_log::init_access_log(Some(&app_data_dir));
             }
 
             tauri::async_runtime::spawn(async move {
@@ -45,6 +71,8 @@ fn main() {
                 .await;
             });
 
+            supervisor::spawn(app.app_handle().clone());
+
             // Show window after content is loaded
             if let Some(window) = app.get_webview_window("main") {
                 #[cfg(debug_assertions)]
@@ -0,0 +1,239 @@
+// Active health-check supervisor for the Flask sidecar: polls `/api/health`
+// on an interval (complementing the passive crash detection in `commands`,
+// which only reacts once the child process has already exited), tracks how
+// long the server has been unhealthy, and restarts it with exponential
+// backoff once it has stayed unhealthy past `UNHEALTHY_TIMEOUT_SECS`. Shares
+// `commands::RESTART_IN_PROGRESS` with the passive restart path so only one
+// of the two ever drives an actual restart at a time.
+use crate::client::PythonServerClient;
+use crate::commands::{start_python_server, stop_python_server_internal, PythonServer, ServerBackend};
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager};
+use tokio::sync::Mutex as TokioMutex;
+
+const POLL_INTERVAL_SECS: u64 = 5;
+const FAILURE_THRESHOLD: u32 = 3;
+const UNHEALTHY_TIMEOUT_SECS: u64 = 35;
+const BACKOFF_BASE_MS: u64 = 1000;
+const BACKOFF_MAX_MS: u64 = 30_000;
+const MAX_RESTART_ATTEMPTS: u32 = 6;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SupervisorHealth {
+    Healthy,
+    Degraded,
+    Restarting,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SupervisorState {
+    pub health: SupervisorHealth,
+    pub consecutive_failures: u32,
+    pub unhealthy_since: Option<u64>,
+    pub last_restart_at: Option<u64>,
+    pub restart_count: u32,
+}
+
+impl SupervisorState {
+    fn new() -> Self {
+        Self {
+            health: SupervisorHealth::Healthy,
+            consecutive_failures: 0,
+            unhealthy_since: None,
+            last_restart_at: None,
+            restart_count: 0,
+        }
+    }
+}
+
+pub struct Supervisor {
+    state: TokioMutex<SupervisorState>,
+}
+
+impl Supervisor {
+    pub fn new() -> Self {
+        Self {
+            state: TokioMutex::new(SupervisorState::new()),
+        }
+    }
+
+    pub async fn snapshot(&self) -> SupervisorState {
+        self.state.lock().await.clone()
+    }
+}
+
+fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}
+
+/// Drives the supervisor's poll loop one step at a time so it can be
+/// registered with the `WorkerManager` instead of a bare `async_runtime::spawn`.
+struct HealthSupervisorWorker {
+    app_handle: AppHandle,
+}
+
+impl crate::worker::BackgroundWorker for HealthSupervisorWorker {
+    async fn run_step(&mut self) -> crate::worker::WorkerState {
+        tokio::time::sleep(std::time::Duration::from_secs(POLL_INTERVAL_SECS)).await;
+        poll_once(&self.app_handle).await;
+        crate::worker::WorkerState::Active
+    }
+}
+
+/// Registers the supervisor's poll loop with the `WorkerManager` so it shows
+/// up in `list_workers` and can be paused/cancelled like any other worker.
+pub fn spawn(app_handle: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        let Some(manager) = app_handle.try_state::<crate::worker::WorkerManager>() else {
+            return;
+        };
+        manager
+            .register(
+                "flask-health-supervisor",
+                HealthSupervisorWorker {
+                    app_handle: app_handle.clone(),
+                },
+            )
+            .await;
+    });
+}
+
+async fn resolve_base_url_and_token(app_handle: &AppHandle) -> Option<(String, Option<String>, bool)> {
+    let server_state = app_handle.try_state::<TokioMutex<PythonServer>>()?;
+    let server = server_state.lock().await;
+    if server.commanded_stop {
+        return None;
+    }
+    let is_local = matches!(server.backend, ServerBackend::Local);
+    let base_url = match &server.backend {
+        ServerBackend::Remote { base_url } => base_url.trim_end_matches('/').to_string(),
+        ServerBackend::Local => format!("http://localhost:{}", server.port?),
+    };
+    Some((base_url, server.token.clone(), is_local))
+}
+
+async fn poll_once(app_handle: &AppHandle) {
+    let Some(supervisor) = app_handle.try_state::<Supervisor>() else {
+        return;
+    };
+
+    let Some((base_url, token, is_local)) = resolve_base_url_and_token(app_handle).await else {
+        return;
+    };
+
+    let Ok(client) = PythonServerClient::new(&base_url, token) else {
+        return;
+    };
+
+    match client.health().await {
+        Ok(_) => {
+            let mut state = supervisor.state.lock().await;
+            let was_degraded = state.consecutive_failures > 0;
+            state.consecutive_failures = 0;
+            state.unhealthy_since = None;
+            state.health = SupervisorHealth::Healthy;
+            drop(state);
+            if was_degraded {
+                let _ = app_handle.emit("server://up", ());
+            }
+        }
+        Err(_) => {
+            let should_restart = {
+                let mut state = supervisor.state.lock().await;
+                state.consecutive_failures += 1;
+                state.health = SupervisorHealth::Degraded;
+                let unhealthy_since = *state.unhealthy_since.get_or_insert_with(now_secs);
+
+                state.consecutive_failures >= FAILURE_THRESHOLD
+                    && now_secs().saturating_sub(unhealthy_since) >= UNHEALTHY_TIMEOUT_SECS
+            };
+
+            // A remote backend isn't ours to restart: `start_python_server`
+            // would just re-adopt the same unreachable URL and report
+            // success without ever checking it's actually back up, marking
+            // the supervisor falsely healthy. Leave it `Degraded` instead of
+            // driving it through the local restart chain.
+            if should_restart && !is_local {
+                crate::logger::log_warn(
+                    "[SUPERVISOR] Remote backend unhealthy past timeout; not auto-restarting a server we don't own",
+                );
+            } else if should_restart {
+                if crate::commands::RESTART_IN_PROGRESS
+                    .compare_exchange(
+                        false,
+                        true,
+                        std::sync::atomic::Ordering::SeqCst,
+                        std::sync::atomic::Ordering::SeqCst,
+                    )
+                    .is_ok()
+                {
+                    restart_with_backoff(app_handle.clone(), 0).await;
+                    crate::commands::RESTART_IN_PROGRESS
+                        .store(false, std::sync::atomic::Ordering::SeqCst);
+                } else {
+                    crate::logger::log_warn(
+                        "[SUPERVISOR] Health-check supervisor saw a restart already in progress elsewhere, skipping",
+                    );
+                }
+            }
+        }
+    }
+}
+
+/// Callers must hold `crate::commands::RESTART_IN_PROGRESS` before calling
+/// this, so this restart chain and the passive crash-detection restart in
+/// `commands.rs` can't run against the sidecar at the same time.
+fn restart_with_backoff(
+    app_handle: AppHandle,
+    attempt: u32,
+) -> std::pin::Pin<Box<dyn std::future::Future<Output = ()> + Send>> {
+    Box::pin(async move {
+        let Some(supervisor) = app_handle.try_state::<Supervisor>() else {
+            return;
+        };
+
+        if attempt >= MAX_RESTART_ATTEMPTS {
+            crate::logger::log_error(&format!(
+                "[SUPERVISOR] Health-check supervisor giving up after {} restart attempts",
+                attempt
+            ));
+            return;
+        }
+
+        {
+            let mut state = supervisor.state.lock().await;
+            state.health = SupervisorHealth::Restarting;
+        }
+        let _ = app_handle.emit("server://restarting", attempt + 1);
+        let _ = app_handle.emit("server://down", ());
+
+        let backoff_ms = (BACKOFF_BASE_MS << attempt.min(6)).min(BACKOFF_MAX_MS);
+        tokio::time::sleep(std::time::Duration::from_millis(backoff_ms)).await;
+
+        let Some(server_state) = app_handle.try_state::<TokioMutex<PythonServer>>() else {
+            return;
+        };
+
+        let _ = stop_python_server_internal(&app_handle, &server_state).await;
+
+        match start_python_server(app_handle.clone(), server_state).await {
+            Ok(response) if response.success => {
+                let mut state = supervisor.state.lock().await;
+                state.health = SupervisorHealth::Healthy;
+                state.consecutive_failures = 0;
+                state.unhealthy_since = None;
+                state.last_restart_at = Some(now_secs());
+                state.restart_count += 1;
+                drop(state);
+                let _ = app_handle.emit("server://up", ());
+            }
+            _ => {
+                restart_with_backoff(app_handle, attempt + 1).await;
+            }
+        }
+    })
+}
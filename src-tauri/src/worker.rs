@@ -0,0 +1,173 @@
+// Generic background-worker registry: every long-lived `tauri::async_runtime::spawn`
+// task in this app used to be unobservable and uncontrollable from the UI.
+// `BackgroundWorker` gives each one a single-step interface the manager can
+// drive, pause, and cancel, with its status recorded for `list_workers`.
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::{mpsc, Mutex as TokioMutex};
+
+/// Outcome of a single `run_step` call.
+pub enum WorkerState {
+    Active,
+    Idle,
+    Dead(String),
+}
+
+/// A unit of long-lived background work driven one step at a time by the
+/// `WorkerManager`. Implementors own their own pacing (e.g. sleeping between
+/// polls) inside `run_step`.
+pub trait BackgroundWorker: Send {
+    fn run_step(&mut self) -> impl std::future::Future<Output = WorkerState> + Send;
+}
+
+/// Commands a caller can send to a registered worker.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WorkerControl {
+    Start,
+    Pause,
+    Cancel,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WorkerStateLabel {
+    Active,
+    Idle,
+    Dead,
+}
+
+/// Point-in-time status of a registered worker, as reported by `list_workers`.
+#[derive(Debug, Clone, Serialize)]
+pub struct WorkerStatus {
+    pub name: String,
+    pub state: WorkerStateLabel,
+    pub last_error: Option<String>,
+    pub iterations: u64,
+    pub started_at: u64,
+}
+
+struct WorkerHandle {
+    control: mpsc::Sender<WorkerControl>,
+    status: Arc<TokioMutex<WorkerStatus>>,
+}
+
+/// Tauri-managed registry of named background workers.
+#[derive(Default)]
+pub struct WorkerManager {
+    workers: TokioMutex<HashMap<String, WorkerHandle>>,
+}
+
+impl WorkerManager {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a worker under `name` and spawns the task that drives it.
+    /// Replaces any previous worker registered under the same name.
+    pub async fn register<W>(&self, name: &str, worker: W)
+    where
+        W: BackgroundWorker + 'static,
+    {
+        let (control_tx, control_rx) = mpsc::channel(8);
+        let status = Arc::new(TokioMutex::new(WorkerStatus {
+            name: name.to_string(),
+            state: WorkerStateLabel::Active,
+            last_error: None,
+            iterations: 0,
+            started_at: now_secs(),
+        }));
+
+        let previous = self.workers.lock().await.insert(
+            name.to_string(),
+            WorkerHandle {
+                control: control_tx,
+                status: status.clone(),
+            },
+        );
+        if let Some(previous) = previous {
+            let _ = previous.control.try_send(WorkerControl::Cancel);
+        }
+
+        tauri::async_runtime::spawn(drive_worker(worker, control_rx, status));
+    }
+
+    pub async fn list_workers(&self) -> Vec<WorkerStatus> {
+        let workers = self.workers.lock().await;
+        let mut statuses = Vec::with_capacity(workers.len());
+        for handle in workers.values() {
+            statuses.push(handle.status.lock().await.clone());
+        }
+        statuses
+    }
+
+    /// Sends `action` to the named worker's control channel. Returns `false`
+    /// if no worker is registered under that name or its task has already
+    /// stopped.
+    pub async fn control_worker(&self, name: &str, action: WorkerControl) -> bool {
+        let workers = self.workers.lock().await;
+        match workers.get(name) {
+            Some(handle) => handle.control.send(action).await.is_ok(),
+            None => false,
+        }
+    }
+}
+
+async fn drive_worker(
+    mut worker: impl BackgroundWorker,
+    mut control_rx: mpsc::Receiver<WorkerControl>,
+    status: Arc<TokioMutex<WorkerStatus>>,
+) {
+    let mut paused = false;
+
+    loop {
+        while let Ok(action) = control_rx.try_recv() {
+            match action {
+                WorkerControl::Start => paused = false,
+                WorkerControl::Pause => paused = true,
+                WorkerControl::Cancel => {
+                    let mut s = status.lock().await;
+                    s.state = WorkerStateLabel::Dead;
+                    s.last_error = Some("cancelled".to_string());
+                    return;
+                }
+            }
+        }
+
+        if paused {
+            {
+                let mut s = status.lock().await;
+                s.state = WorkerStateLabel::Idle;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+            continue;
+        }
+
+        match worker.run_step().await {
+            WorkerState::Active => {
+                let mut s = status.lock().await;
+                s.state = WorkerStateLabel::Active;
+                s.iterations += 1;
+            }
+            WorkerState::Idle => {
+                let mut s = status.lock().await;
+                s.state = WorkerStateLabel::Idle;
+                s.iterations += 1;
+            }
+            WorkerState::Dead(reason) => {
+                let mut s = status.lock().await;
+                s.state = WorkerStateLabel::Dead;
+                s.last_error = Some(reason);
+                return;
+            }
+        }
+    }
+}
+
+fn now_secs() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .unwrap()
+        .as_secs()
+}